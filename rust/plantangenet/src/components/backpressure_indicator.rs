@@ -0,0 +1,19 @@
+// Copyright (c) 1998-2025 Scott Russell
+// SPDX-License-Identifier: MIT
+
+use super::ClockComponent;
+use crate::{ClockPhase, ClockState};
+
+/// A warning shown while the clock is `Blocked` by backpressure, flashing
+/// on the tick it first enters `Blocked`.
+pub struct BackpressureIndicator;
+
+impl ClockComponent for BackpressureIndicator {
+    fn render(&self, state: &ClockState) -> String {
+        match (state.phase, state.previous_phase) {
+            (ClockPhase::Blocked, ClockPhase::Blocked) => "🚦 backpressure".to_string(),
+            (ClockPhase::Blocked, _) => "🚨 backpressure!".to_string(),
+            _ => String::new(),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+// Copyright (c) 1998-2025 Scott Russell
+// SPDX-License-Identifier: MIT
+
+use super::ClockComponent;
+use crate::{ClockPhase, ClockState};
+
+/// The main clock readout: the current stamp and a glyph for the clock's
+/// current phase.
+pub struct ClockFace;
+
+impl ClockComponent for ClockFace {
+    fn render(&self, state: &ClockState) -> String {
+        // No tick has ever arrived, so there's nothing to reconnect to yet
+        // (phase defaults to `Disconnected` before the first connect
+        // attempt even resolves) — that's "waiting", not "lost".
+        let Some(tick) = &state.tick else {
+            return "🔌 Waiting for clock.tick...".to_string();
+        };
+
+        let glyph = match state.phase {
+            ClockPhase::Disconnected => return "🔌 reconnecting...".to_string(),
+            ClockPhase::Running => "▶️",
+            ClockPhase::Suspended => "⏸",
+            ClockPhase::Blocked => "🚦",
+            ClockPhase::Stepping => "⏭",
+            ClockPhase::Finished => "🏁",
+        };
+
+        format!("🕒 {:.2} | {}", tick.stamp, glyph)
+    }
+}
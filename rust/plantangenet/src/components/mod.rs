@@ -0,0 +1,18 @@
+// Copyright (c) 1998-2025 Scott Russell
+// SPDX-License-Identifier: MIT
+
+pub mod backpressure_indicator;
+pub mod clock_face;
+pub mod transport_bar;
+
+pub use backpressure_indicator::BackpressureIndicator;
+pub use clock_face::ClockFace;
+pub use transport_bar::TransportBar;
+
+use crate::ClockState;
+
+/// A single piece of the player's UI, independent of every other one.
+pub trait ClockComponent: Send + Sync {
+    /// An empty string means "nothing to show right now".
+    fn render(&self, state: &ClockState) -> String;
+}
@@ -0,0 +1,21 @@
+// Copyright (c) 1998-2025 Scott Russell
+// SPDX-License-Identifier: MIT
+
+use super::ClockComponent;
+use crate::ClockState;
+
+/// The commands currently advertised in `TickMessage.transport`, with the
+/// active `current_choice` highlighted.
+pub struct TransportBar;
+
+impl ClockComponent for TransportBar {
+    fn render(&self, state: &ClockState) -> String {
+        match &state.tick {
+            Some(tick) if !tick.transport.is_empty() => {
+                let current = tick.current_choice.as_deref().unwrap_or("-");
+                format!("⏯ [{}] -> {}", tick.transport.join(" "), current)
+            }
+            _ => String::new(),
+        }
+    }
+}
@@ -0,0 +1,126 @@
+// Copyright (c) 1998-2025 Scott Russell
+// SPDX-License-Identifier: MIT
+
+use anyhow::{bail, Result};
+use async_nats::{Client, ConnectOptions};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+const INITIAL_BACKOFF: Duration = Duration::from_millis(250);
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// A transport command a conductor can issue back to the clock.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClockCommand {
+    Pause,
+    Resume,
+    Step,
+    SetInterval(f64),
+}
+
+impl ClockCommand {
+    fn subject(&self) -> &'static str {
+        match self {
+            ClockCommand::Pause => "clock.pause",
+            ClockCommand::Resume => "clock.resume",
+            ClockCommand::Step => "clock.step",
+            ClockCommand::SetInterval(_) => "clock.set_interval",
+        }
+    }
+
+    /// The name this command is advertised under in `TickMessage.transport`.
+    fn transport_name(&self) -> &'static str {
+        match self {
+            ClockCommand::Pause => "pause",
+            ClockCommand::Resume => "resume",
+            ClockCommand::Step => "step",
+            ClockCommand::SetInterval(_) => "set_interval",
+        }
+    }
+
+    fn payload(&self) -> Vec<u8> {
+        match self {
+            ClockCommand::SetInterval(interval) => interval.to_string().into_bytes(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Publishes clock transport commands over NATS. Commands are only sent
+/// when advertised in the latest tick's `transport` list, since the
+/// emitting side may not support every command at every moment (e.g.
+/// `step` while already stepping).
+#[derive(Clone, bevy::prelude::Resource)]
+pub struct ClockController {
+    client: Client,
+}
+
+impl ClockController {
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    /// Connect to `url`, retrying with exponential backoff (capped at
+    /// `MAX_BACKOFF`) instead of failing the caller on the first attempt,
+    /// the same way `start_tick_listener` handles its own connect.
+    pub async fn connect_with_backoff(url: &str) -> Self {
+        let mut backoff = INITIAL_BACKOFF;
+        loop {
+            match ConnectOptions::new().connect(url).await {
+                Ok(client) => return Self::new(client),
+                Err(e) => {
+                    eprintln!(
+                        "clock controller connect error: {} (retrying in {:?})",
+                        e, backoff
+                    );
+                    tokio::time::sleep(backoff).await;
+                    backoff = (backoff * 2).min(MAX_BACKOFF);
+                }
+            }
+        }
+    }
+
+    /// Send `command`, rejecting it if `transport` doesn't advertise support.
+    pub async fn send(&self, command: ClockCommand, transport: &[String]) -> Result<()> {
+        if !transport.iter().any(|t| t == command.transport_name()) {
+            bail!(
+                "clock transport does not currently advertise `{}`",
+                command.transport_name()
+            );
+        }
+
+        self.client
+            .publish(command.subject(), command.payload().into())
+            .await?;
+        Ok(())
+    }
+}
+
+/// Holds a `ClockController` that connects on a background thread, so
+/// callers can be inserted as a resource (or cloned into another thread)
+/// before the connect has resolved, the same way `SharedClockState` lets
+/// `update_clock` publish state the Bevy app reads at its own pace.
+#[derive(Clone, Default, bevy::prelude::Resource)]
+pub struct SharedClockController(pub Arc<Mutex<Option<ClockController>>>);
+
+impl SharedClockController {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The controller, once `connect_with_backoff` has resolved.
+    pub fn get(&self) -> Option<ClockController> {
+        self.0.lock().ok().and_then(|guard| guard.clone())
+    }
+
+    fn set(&self, controller: ClockController) {
+        if let Ok(mut guard) = self.0.lock() {
+            *guard = Some(controller);
+        }
+    }
+
+    /// Connect in the background and populate `self` once it succeeds.
+    pub async fn connect_with_backoff(&self, url: &str) {
+        self.set(ClockController::connect_with_backoff(url).await);
+    }
+}
@@ -0,0 +1,6 @@
+// Copyright (c) 1998-2025 Scott Russell
+// SPDX-License-Identifier: MIT
+
+pub mod control;
+pub mod state;
+pub mod tick;
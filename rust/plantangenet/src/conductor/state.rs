@@ -1,14 +1,55 @@
 // Copyright (c) 1998-2025 Scott Russell
 // SPDX-License-Identifier: MIT
 
-use crate::{ClockState, SharedClockState};
+use crate::{ClockPhase, ClockState, SharedClockState, TickMessage};
 use std::sync::MutexGuard;
 
-pub fn update_clock(state: &SharedClockState, stamp: f64, paused: bool) {
+/// Derive the clock's lifecycle phase from a tick and the phase it's
+/// transitioning from, in priority order: `Finished` (terminal) beats
+/// `Blocked` (stalled by backpressure), which beats `Stepping`, which beats
+/// `Suspended`. `Blocked` always takes precedence over `Suspended` when
+/// both conditions hold.
+///
+/// A completed `Stepping` tick returns to `Suspended` regardless of
+/// `tick.paused`, since stepping is inherently "advance once while paused".
+fn compute_phase(tick: &TickMessage, previous: ClockPhase) -> ClockPhase {
+    if tick.disposition.as_deref() == Some("finished") {
+        ClockPhase::Finished
+    } else if tick.backpressure {
+        ClockPhase::Blocked
+    } else if tick.stepping {
+        ClockPhase::Stepping
+    } else if previous == ClockPhase::Stepping || tick.paused {
+        ClockPhase::Suspended
+    } else {
+        ClockPhase::Running
+    }
+}
+
+/// Store the full decoded tick, so downstream systems (transport, phase,
+/// rendering) can read anything `TickMessage` carries, not just the stamp.
+pub fn update_clock(state: &SharedClockState, tick: TickMessage) {
     if let Ok(mut lock) = state.0.lock() {
-        lock.stamp = stamp;
-        lock.paused = paused;
+        let previous = lock.phase;
+        lock.phase = compute_phase(&tick, previous);
+        lock.previous_phase = previous;
+        lock.accumulators = tick.accumulators.clone();
+        lock.tick = Some(tick);
         lock.last_update = Some(std::time::Instant::now());
+        lock.connected = true;
+    }
+}
+
+/// Flip the connectivity flag, keeping the last tick in place. On disconnect
+/// the phase also moves to `Disconnected` (remembering the old one in
+/// `previous_phase`), so `ClockFace` can distinguish "stalled" from "gone".
+pub fn set_connected(state: &SharedClockState, connected: bool) {
+    if let Ok(mut lock) = state.0.lock() {
+        lock.connected = connected;
+        if !connected {
+            lock.previous_phase = lock.phase;
+            lock.phase = ClockPhase::Disconnected;
+        }
     }
 }
 
@@ -10,6 +10,6 @@ use anyhow::Result;
 
 pub async fn handle_tick(msg: &Message, shared: &SharedClockState) -> Result<()> {
     let tick: TickMessage = serde_json::from_slice(&msg.payload)?;
-    update_clock(shared, tick.stamp, tick.paused);
+    update_clock(shared, tick);
     Ok(())
 }
\ No newline at end of file
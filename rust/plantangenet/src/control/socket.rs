@@ -0,0 +1,134 @@
+// Copyright (c) 1998-2025 Scott Russell
+// SPDX-License-Identifier: MIT
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{UnixListener, UnixStream};
+
+use crate::conductor::control::{ClockCommand, SharedClockController};
+use crate::{ClockPhase, ClockState, SharedClockState, TickMessage};
+
+pub const DEFAULT_SOCKET_PATH: &str = "/tmp/plantangenet.sock";
+
+/// A request a local script can issue over the control socket.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum Request {
+    GetState,
+    Pause,
+    Resume,
+    Step,
+}
+
+/// The server's reply to a `Request`.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum Response {
+    State(ClockSnapshot),
+    Ack,
+    Error(String),
+}
+
+/// A serializable copy of `ClockState`, since `ClockState` itself carries a
+/// non-serializable `Instant`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClockSnapshot {
+    pub tick: Option<TickMessage>,
+    pub connected: bool,
+    pub phase: ClockPhase,
+}
+
+impl From<&ClockState> for ClockSnapshot {
+    fn from(state: &ClockState) -> Self {
+        Self {
+            tick: state.tick.clone(),
+            connected: state.connected,
+            phase: state.phase,
+        }
+    }
+}
+
+/// Bind `path` and serve the control protocol until the process exits.
+pub async fn run_socket_server(
+    path: &str,
+    state: SharedClockState,
+    controller: SharedClockController,
+) -> Result<()> {
+    let _ = std::fs::remove_file(path);
+    let listener = UnixListener::bind(path)?;
+    println!("Control socket listening on {}", path);
+
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let state = state.clone();
+        let controller = controller.clone();
+        tokio::spawn(async move {
+            if let Err(e) = handle_connection(stream, state, controller).await {
+                eprintln!("control socket connection error: {}", e);
+            }
+        });
+    }
+}
+
+async fn handle_connection(
+    mut stream: UnixStream,
+    state: SharedClockState,
+    controller: SharedClockController,
+) -> Result<()> {
+    loop {
+        let request: Request = match read_frame(&mut stream).await {
+            Ok(request) => request,
+            Err(_) => return Ok(()),
+        };
+
+        let response = handle_request(request, &state, &controller).await;
+        write_frame(&mut stream, &response).await?;
+    }
+}
+
+async fn handle_request(
+    request: Request,
+    state: &SharedClockState,
+    controller: &SharedClockController,
+) -> Response {
+    let command = match request {
+        Request::GetState => {
+            return match state.0.lock() {
+                Ok(lock) => Response::State(ClockSnapshot::from(&*lock)),
+                Err(_) => Response::Error("clock state lock poisoned".into()),
+            };
+        }
+        Request::Pause => ClockCommand::Pause,
+        Request::Resume => ClockCommand::Resume,
+        Request::Step => ClockCommand::Step,
+    };
+
+    let Some(controller) = controller.get() else {
+        return Response::Error("clock controller not connected yet".into());
+    };
+
+    let transport = state
+        .0
+        .lock()
+        .ok()
+        .and_then(|lock| lock.tick.as_ref().map(|tick| tick.transport.clone()))
+        .unwrap_or_default();
+
+    match controller.send(command, &transport).await {
+        Ok(()) => Response::Ack,
+        Err(e) => Response::Error(e.to_string()),
+    }
+}
+
+async fn read_frame<T: for<'de> Deserialize<'de>>(stream: &mut UnixStream) -> Result<T> {
+    let len = stream.read_u32_le().await?;
+    let mut buf = vec![0u8; len as usize];
+    stream.read_exact(&mut buf).await?;
+    Ok(bincode::deserialize(&buf)?)
+}
+
+async fn write_frame<T: Serialize>(stream: &mut UnixStream, value: &T) -> Result<()> {
+    let buf = bincode::serialize(value)?;
+    stream.write_u32_le(buf.len() as u32).await?;
+    stream.write_all(&buf).await?;
+    Ok(())
+}
@@ -1,12 +1,12 @@
 // Copyright (c) 1998-2025 Scott Russell
 // SPDX-License-Identifier: MIT
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct TickMessage {
     pub id: String,
     pub short_id: String,
@@ -26,7 +26,7 @@ pub struct TickMessage {
     pub accumulators: HashMap<String, AccumulatorData>,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct AccumulatorData {
     pub interval: f64,
     pub elapsed: f64,
@@ -35,17 +35,50 @@ pub struct AccumulatorData {
     pub repeating: bool,
 }
 
+/// Unlike a bare `paused` flag, this distinguishes *why* the clock isn't
+/// simply running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ClockPhase {
+    /// Advancing normally.
+    Running,
+    /// Paused by user/conductor request (`TickMessage.paused`).
+    Suspended,
+    /// Stalled by backpressure (`TickMessage.backpressure`). Takes
+    /// precedence over `Suspended` when both conditions hold.
+    Blocked,
+    /// Advancing exactly one tick (`TickMessage.stepping`).
+    Stepping,
+    /// The clock has reported it's done (`TickMessage.disposition`).
+    Finished,
+    /// No tick has arrived, or the listener has lost its connection.
+    Disconnected,
+}
+
+impl Default for ClockPhase {
+    fn default() -> Self {
+        ClockPhase::Disconnected
+    }
+}
+
 #[derive(Default, Clone)]
 pub struct ClockState {
-    pub stamp: f64,
-    pub paused: bool,
+    pub tick: Option<TickMessage>,
+    pub connected: bool,
     pub last_update: Option<Instant>,
+    pub phase: ClockPhase,
+    /// The phase as of the previous tick (or disconnect), so components can
+    /// detect a transition instead of just the current phase.
+    pub previous_phase: ClockPhase,
+    /// The latest `TickMessage.accumulators`, kept alongside `tick` so the
+    /// accumulator panel can diff against it without unwrapping `tick`.
+    pub accumulators: HashMap<String, AccumulatorData>,
 }
 
 #[derive(bevy::prelude::Resource, Clone)]
 pub struct SharedClockState(pub Arc<Mutex<ClockState>>);
 
+pub mod components;
+pub mod control;
 pub mod nats;
 pub mod systems;
 pub mod conductor;
-pub mod resources;
@@ -1,25 +1,74 @@
 // Copyright (c) 1998-2025 Scott Russell
 // SPDX-License-Identifier: MIT
 
+use bevy::app::AppExit;
 use bevy::prelude::*;
-use plantangenet::nats::listener::start_tick_listener;
+use plantangenet::conductor::control::SharedClockController;
+use plantangenet::control::socket::{run_socket_server, DEFAULT_SOCKET_PATH};
+use plantangenet::nats::listener::{start_tick_listener_with_shutdown, ListenerShutdown};
+use plantangenet::systems::accumulators::{setup_accumulator_panel, sync_accumulator_panel};
+use plantangenet::systems::input::conductor_input;
+use plantangenet::systems::render::{render_components, ComponentRegistry};
+use plantangenet::systems::setup::setup;
 use plantangenet::ClockState;
 use plantangenet::SharedClockState;
-use plantangenet::systems::setup::setup;
-use plantangenet::systems::update_display::update_display;
 use std::sync::{Arc, Mutex};
 
+#[derive(Resource, Clone)]
+struct ListenerShutdownResource(ListenerShutdown);
+
+/// Stop the background listener thread when the Bevy app is closing, rather
+/// than leaking it for the remaining lifetime of the process.
+fn shutdown_listener_on_exit(
+    mut exit_events: EventReader<AppExit>,
+    shutdown: Res<ListenerShutdownResource>,
+) {
+    if exit_events.read().next().is_some() {
+        shutdown.0.shutdown();
+    }
+}
+
 fn main() {
     let shared_state = SharedClockState(Arc::new(Mutex::new(ClockState::default())));
+    let listener_shutdown = ListenerShutdown::new();
+    {
+        let state_clone = shared_state.clone();
+        let shutdown_clone = listener_shutdown.clone();
+        std::thread::spawn(move || {
+            pollster::block_on(start_tick_listener_with_shutdown(
+                state_clone,
+                shutdown_clone,
+            ));
+        });
+    }
+
+    let shared_controller = SharedClockController::new();
+    {
+        let controller_clone = shared_controller.clone();
+        std::thread::spawn(move || {
+            pollster::block_on(controller_clone.connect_with_backoff("nats://127.0.0.1:4222"));
+        });
+    }
+
     {
         let state_clone = shared_state.clone();
+        let controller_clone = shared_controller.clone();
         std::thread::spawn(move || {
-            pollster::block_on(start_tick_listener(state_clone));
+            pollster::block_on(async move {
+                if let Err(e) =
+                    run_socket_server(DEFAULT_SOCKET_PATH, state_clone, controller_clone).await
+                {
+                    eprintln!("control socket server error: {}", e);
+                }
+            });
         });
     }
 
     App::new()
         .insert_resource(shared_state)
+        .insert_resource(shared_controller)
+        .insert_resource(ListenerShutdownResource(listener_shutdown))
+        .init_resource::<ComponentRegistry>()
         .add_plugins(DefaultPlugins.set(WindowPlugin {
             primary_window: Some(Window {
                 title: "🌀 Plantangenet Player".into(),
@@ -28,7 +77,11 @@ fn main() {
             }),
             ..default()
         }))
-        .add_systems(Startup, setup )
-        .add_systems(Update, update_display)
+        .add_systems(Startup, (setup, setup_accumulator_panel))
+        .add_systems(
+            Update,
+            (render_components, conductor_input, sync_accumulator_panel),
+        )
+        .add_systems(Last, shutdown_listener_on_exit)
         .run();
 }
@@ -1,27 +1,117 @@
 // Copyright (c) 1998-2025 Scott Russell
 // SPDX-License-Identifier: MIT
 
+use crate::conductor::state::set_connected;
+use crate::conductor::tick::handle_tick;
 use crate::SharedClockState;
 use async_nats::ConnectOptions;
 use futures_util::stream::StreamExt;
-use crate::conductor::tick::handle_tick;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Notify;
 
-pub async fn start_tick_listener(state: SharedClockState) {
-    let client = ConnectOptions::new()
-        .connect("nats://127.0.0.1:4222")
-        .await
-        .expect("failed to connect to NATS");
+const NATS_URL: &str = "nats://127.0.0.1:4222";
+const INITIAL_BACKOFF: Duration = Duration::from_millis(250);
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// A handle that lets the Bevy app stop the background listener thread
+/// cleanly on exit, rather than leaking it for the lifetime of the process.
+#[derive(Clone)]
+pub struct ListenerShutdown {
+    flag: Arc<AtomicBool>,
+    notify: Arc<Notify>,
+}
+
+impl ListenerShutdown {
+    pub fn new() -> Self {
+        Self {
+            flag: Arc::new(AtomicBool::new(false)),
+            notify: Arc::new(Notify::new()),
+        }
+    }
 
-    let mut subscriber = client
-        .subscribe("clock.tick".into())
-        .await
-        .expect("failed to subscribe");
+    pub fn shutdown(&self) {
+        self.flag.store(true, Ordering::SeqCst);
+        // `notify_one` keeps a permit if nobody is waiting yet (e.g. while
+        // connecting), so a shutdown fired then is not lost.
+        self.notify.notify_one();
+    }
+
+    fn is_shutdown(&self) -> bool {
+        self.flag.load(Ordering::SeqCst)
+    }
+}
+
+impl Default for ListenerShutdown {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
+/// Connect, subscribe and consume `clock.tick` until the stream ends, the
+/// broker drops us, or `shutdown` fires. Any of those returns control to the
+/// caller so it can decide whether to reconnect.
+async fn run_once(state: &SharedClockState, shutdown: &ListenerShutdown) -> anyhow::Result<()> {
+    let client = ConnectOptions::new().connect(NATS_URL).await?;
+    let mut subscriber = client.subscribe("clock.tick".into()).await?;
+
+    set_connected(state, true);
     println!("Listening for clock.tick messages...");
 
-    while let Some(message) = subscriber.next().await {
-        if let Err(e) = handle_tick(&message, &state).await {
-            eprintln!("Error handling tick message: {}", e);
+    loop {
+        tokio::select! {
+            message = subscriber.next() => {
+                match message {
+                    Some(message) => {
+                        if let Err(e) = handle_tick(&message, state).await {
+                            eprintln!("Error handling tick message: {}", e);
+                        }
+                    }
+                    None => return Ok(()),
+                }
+            }
+            _ = shutdown.notify.notified() => return Ok(()),
+        }
+    }
+}
+
+/// Run `run_once` in a loop, reconnecting with exponential backoff (capped
+/// at `MAX_BACKOFF`) whenever the connection drops, and resetting the
+/// backoff after every clean connect. While disconnected, `ClockState.connected`
+/// is false and the phase is `Disconnected`, so `ClockFace` shows a "reconnecting..." indicator
+/// instead of a stale clock.
+pub async fn start_tick_listener(state: SharedClockState) {
+    start_tick_listener_with_shutdown(state, ListenerShutdown::new()).await
+}
+
+/// Same as `start_tick_listener`, but abortable via the given `shutdown`
+/// handle so callers can stop the background thread on exit.
+pub async fn start_tick_listener_with_shutdown(state: SharedClockState, shutdown: ListenerShutdown) {
+    let mut backoff = INITIAL_BACKOFF;
+
+    while !shutdown.is_shutdown() {
+        match run_once(&state, &shutdown).await {
+            Ok(()) => backoff = INITIAL_BACKOFF,
+            Err(e) => {
+                eprintln!(
+                    "clock.tick listener error: {} (reconnecting in {:?})",
+                    e, backoff
+                );
+            }
+        }
+
+        set_connected(&state, false);
+        if shutdown.is_shutdown() {
+            break;
         }
+
+        tokio::select! {
+            _ = tokio::time::sleep(backoff) => {}
+            _ = shutdown.notify.notified() => break,
+        }
+        backoff = (backoff * 2).min(MAX_BACKOFF);
     }
+
+    set_connected(&state, false);
 }
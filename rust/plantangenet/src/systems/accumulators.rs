@@ -0,0 +1,186 @@
+// Copyright (c) 1998-2025 Scott Russell
+// SPDX-License-Identifier: MIT
+
+use bevy::prelude::*;
+
+use crate::SharedClockState;
+
+const ROW_HEIGHT: f32 = 22.0;
+const BAR_WIDTH: f32 = 160.0;
+const BAR_HEIGHT: f32 = 12.0;
+const PANEL_TOP: f32 = 160.0;
+const PANEL_LEFT: f32 = 20.0;
+
+/// Anchors the accumulator rows, so each row can be positioned relative to
+/// the panel instead of the window.
+#[derive(Component)]
+pub struct AccumulatorPanelRoot;
+
+/// Tags the row spawned for one named accumulator, so `sync_accumulator_panel`
+/// can find it again on a later frame instead of respawning it.
+#[derive(Component)]
+pub struct AccumulatorRow {
+    name: String,
+    fill: Entity,
+    label: Entity,
+}
+
+/// The fill bar inside an `AccumulatorRow`, whose width is set to
+/// `elapsed / interval` as a percentage.
+#[derive(Component)]
+pub struct AccumulatorFill;
+
+/// The text label inside an `AccumulatorRow`.
+#[derive(Component)]
+pub struct AccumulatorLabel;
+
+/// Spawns the (initially empty) panel the accumulator rows are parented to.
+pub fn setup_accumulator_panel(mut commands: Commands) {
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(PANEL_TOP),
+                left: Val::Px(PANEL_LEFT),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(6.0),
+                ..default()
+            },
+            ..default()
+        },
+        AccumulatorPanelRoot,
+    ));
+}
+
+/// Diffs `ClockState.accumulators` against the currently spawned rows:
+/// spawns one per new key, despawns one per removed key, and updates the
+/// rest in place by key, rather than rebuilding the panel every frame.
+pub fn sync_accumulator_panel(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    clock_state: Res<SharedClockState>,
+    panel: Query<Entity, With<AccumulatorPanelRoot>>,
+    rows: Query<(Entity, &AccumulatorRow)>,
+    mut fills: Query<&mut Style, With<AccumulatorFill>>,
+    mut labels: Query<&mut Text, With<AccumulatorLabel>>,
+) {
+    let Ok(panel) = panel.get_single() else {
+        return;
+    };
+    let Ok(state) = clock_state.0.lock() else {
+        return;
+    };
+
+    for (entity, row) in &rows {
+        if !state.accumulators.contains_key(&row.name) {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+
+    for (name, data) in state.accumulators.iter() {
+        let existing = rows.iter().find(|(_, row)| &row.name == name);
+
+        let (fill_entity, label_entity) = match existing {
+            Some((_, row)) => (row.fill, row.label),
+            None => spawn_row(&mut commands, panel, &asset_server, name),
+        };
+
+        let fraction = if data.interval > 0.0 {
+            (data.elapsed / data.interval).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        if let Ok(mut style) = fills.get_mut(fill_entity) {
+            style.width = Val::Percent(fraction * 100.0);
+        }
+
+        if let Ok(mut text) = labels.get_mut(label_entity) {
+            text.sections[0].value = format!(
+                "{} {} x{} {}{}",
+                name,
+                if data.running { "▶" } else { "⏸" },
+                data.cycles,
+                if data.repeating { "🔁" } else { "" },
+                if fraction >= 1.0 { " ✓" } else { "" },
+            );
+        }
+    }
+}
+
+fn spawn_row(
+    commands: &mut Commands,
+    panel: Entity,
+    asset_server: &AssetServer,
+    name: &str,
+) -> (Entity, Entity) {
+    let font: Handle<Font> = asset_server.load("fonts/fa-6-regular-400.otf");
+
+    let fill = commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(0.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                background_color: Color::rgb(0.2, 0.8, 0.3).into(),
+                ..default()
+            },
+            AccumulatorFill,
+        ))
+        .id();
+
+    let bar = commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Px(BAR_WIDTH),
+                height: Val::Px(BAR_HEIGHT),
+                border: UiRect::all(Val::Px(1.0)),
+                ..default()
+            },
+            border_color: Color::GRAY.into(),
+            background_color: Color::rgb(0.15, 0.15, 0.15).into(),
+            ..default()
+        })
+        .add_child(fill)
+        .id();
+
+    let label = commands
+        .spawn((
+            TextBundle::from_section(
+                String::new(),
+                TextStyle {
+                    font,
+                    font_size: 16.0,
+                    color: Color::WHITE,
+                },
+            ),
+            AccumulatorLabel,
+        ))
+        .id();
+
+    let row = commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(8.0),
+                    height: Val::Px(ROW_HEIGHT),
+                    ..default()
+                },
+                ..default()
+            },
+            AccumulatorRow {
+                name: name.to_string(),
+                fill,
+                label,
+            },
+        ))
+        .add_child(bar)
+        .add_child(label)
+        .id();
+
+    commands.entity(panel).add_child(row);
+    (fill, label)
+}
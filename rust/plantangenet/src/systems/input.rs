@@ -0,0 +1,55 @@
+// Copyright (c) 1998-2025 Scott Russell
+// SPDX-License-Identifier: MIT
+
+use bevy::prelude::*;
+use bevy::tasks::IoTaskPool;
+
+use crate::conductor::control::{ClockCommand, SharedClockController};
+use crate::SharedClockState;
+
+/// Maps keyboard transport controls onto `ClockController::send`: space
+/// toggles pause/resume, the left and right arrow keys step the clock one tick.
+pub fn conductor_input(
+    keys: Res<Input<KeyCode>>,
+    clock_state: Res<SharedClockState>,
+    controller: Res<SharedClockController>,
+) {
+    let Some(command) = (if keys.just_pressed(KeyCode::Space) {
+        let paused = clock_state
+            .0
+            .lock()
+            .ok()
+            .and_then(|s| s.tick.as_ref().map(|t| t.paused))
+            .unwrap_or(false);
+        Some(if paused {
+            ClockCommand::Resume
+        } else {
+            ClockCommand::Pause
+        })
+    } else if keys.just_pressed(KeyCode::Left) || keys.just_pressed(KeyCode::Right) {
+        Some(ClockCommand::Step)
+    } else {
+        None
+    }) else {
+        return;
+    };
+
+    let transport = clock_state
+        .0
+        .lock()
+        .ok()
+        .and_then(|s| s.tick.as_ref().map(|t| t.transport.clone()))
+        .unwrap_or_default();
+
+    let Some(controller) = controller.get() else {
+        eprintln!("clock controller not connected yet, ignoring input");
+        return;
+    };
+    IoTaskPool::get()
+        .spawn(async move {
+            if let Err(e) = controller.send(command, &transport).await {
+                eprintln!("Error sending clock command: {}", e);
+            }
+        })
+        .detach();
+}
@@ -0,0 +1,7 @@
+// Copyright (c) 1998-2025 Scott Russell
+// SPDX-License-Identifier: MIT
+
+pub mod accumulators;
+pub mod input;
+pub mod render;
+pub mod setup;
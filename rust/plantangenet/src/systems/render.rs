@@ -0,0 +1,45 @@
+// Copyright (c) 1998-2025 Scott Russell
+// SPDX-License-Identifier: MIT
+
+use bevy::prelude::*;
+
+use crate::components::ClockComponent;
+use crate::SharedClockState;
+
+/// The registered components, in spawn order. Shared as a resource so
+/// `setup` and `render_components` agree on which entity is which.
+#[derive(Resource)]
+pub struct ComponentRegistry(pub Vec<Box<dyn ClockComponent>>);
+
+impl Default for ComponentRegistry {
+    fn default() -> Self {
+        use crate::components::{BackpressureIndicator, ClockFace, TransportBar};
+        Self(vec![
+            Box::new(ClockFace),
+            Box::new(TransportBar),
+            Box::new(BackpressureIndicator),
+        ])
+    }
+}
+
+/// Tags a `TextBundle` with the index of the `ClockComponent` that owns it.
+#[derive(Component)]
+pub struct ClockComponentSlot(pub usize);
+
+/// Re-renders every registered component into its own entity, so adding a
+/// component never requires editing this loop.
+pub fn render_components(
+    clock_state: Res<SharedClockState>,
+    registry: Res<ComponentRegistry>,
+    mut query: Query<(&ClockComponentSlot, &mut Text)>,
+) {
+    let Ok(state) = clock_state.0.lock() else {
+        return;
+    };
+
+    for (slot, mut text) in &mut query {
+        if let Some(component) = registry.0.get(slot.0) {
+            text.sections[0].value = component.render(&state);
+        }
+    }
+}
@@ -3,23 +3,32 @@
 
 use bevy::prelude::*;
 
-pub fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+use crate::systems::render::{ClockComponentSlot, ComponentRegistry};
+
+const LINE_HEIGHT: f32 = 40.0;
+
+pub fn setup(mut commands: Commands, asset_server: Res<AssetServer>, registry: Res<ComponentRegistry>) {
     commands.spawn(Camera2dBundle::default());
 
-    commands.spawn(
-        TextBundle::from_section(
-            "🔌 Waiting for clock.tick...",
-            TextStyle {
-                font: asset_server.load("fonts/fa-6-regular-400.otf"),
-                font_size: 40.0,
-                color: Color::WHITE,
-            },
-        )
-        .with_style(Style {
-            position_type: PositionType::Absolute,
-            top: Val::Px(20.0),
-            left: Val::Px(20.0),
-            ..Default::default()
-        }),
-    );
+    let font: Handle<Font> = asset_server.load("fonts/fa-6-regular-400.otf");
+
+    for (index, _) in registry.0.iter().enumerate() {
+        commands.spawn((
+            TextBundle::from_section(
+                "",
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 32.0,
+                    color: Color::WHITE,
+                },
+            )
+            .with_style(Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(20.0 + index as f32 * LINE_HEIGHT),
+                left: Val::Px(20.0),
+                ..Default::default()
+            }),
+            ClockComponentSlot(index),
+        ));
+    }
 }